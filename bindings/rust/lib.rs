@@ -18,27 +18,56 @@
 //! assert_eq!(text, "HELLO WORLD");
 //! ```
 
-use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_int};
-use std::ptr;
 
 /// Error types for string operations
 #[derive(Debug, Clone, PartialEq)]
 pub enum StringOpsError {
     /// Invalid UTF-8 sequence
     InvalidUtf8,
+    /// A UTF-16 surrogate appeared without its matching pair while decoding CESU-8
+    UnpairedSurrogate,
+    /// A code point outside the Latin-1 range (> U+00FF) cannot be encoded
+    NonLatin1,
 }
 
 impl std::fmt::Display for StringOpsError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             StringOpsError::InvalidUtf8 => write!(f, "Invalid UTF-8 sequence"),
+            StringOpsError::UnpairedSurrogate => write!(f, "Unpaired UTF-16 surrogate in CESU-8 input"),
+            StringOpsError::NonLatin1 => write!(f, "Code point outside the Latin-1 range"),
         }
     }
 }
 
 impl std::error::Error for StringOpsError {}
 
+/// Details of a failed UTF-8 validation, mirroring [`std::str::Utf8Error`].
+///
+/// `valid_up_to` is the number of leading bytes that formed valid UTF-8.
+/// `error_len` is `Some(n)` when an invalid sequence of length `n` can be
+/// resynchronized past (emit U+FFFD and resume at `valid_up_to + n`), or `None`
+/// when the input simply ends in the middle of a character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Utf8ValidationError {
+    /// Number of leading bytes that were valid UTF-8.
+    pub valid_up_to: usize,
+    /// Length of the invalid sequence, or `None` if the input ended mid-character.
+    pub error_len: Option<usize>,
+}
+
+impl std::fmt::Display for Utf8ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.error_len {
+            Some(n) => write!(f, "invalid utf-8 sequence of {} bytes from index {}", n, self.valid_up_to),
+            None => write!(f, "incomplete utf-8 byte sequence from index {}", self.valid_up_to),
+        }
+    }
+}
+
+impl std::error::Error for Utf8ValidationError {}
+
 // Raw FFI declarations
 extern "C" {
     fn neon_to_upper(str: *mut c_char, len: usize);
@@ -60,8 +89,7 @@ extern "C" {
 /// ```
 pub fn to_upper_inplace(text: &mut String) {
     unsafe {
-        let bytes = text.as_mut_vec();
-        neon_to_upper(bytes.as_mut_ptr() as *mut c_char, bytes.len());
+        make_ascii_uppercase(text.as_mut_vec());
     }
 }
 
@@ -78,34 +106,97 @@ pub fn to_upper_inplace(text: &mut String) {
 /// ```
 pub fn to_lower_inplace(text: &mut String) {
     unsafe {
-        let bytes = text.as_mut_vec();
+        make_ascii_lowercase(text.as_mut_vec());
+    }
+}
+
+/// Convert ASCII letters to uppercase in-place in a byte slice.
+///
+/// Drives `neon_to_upper` directly, so data held in a buffer, arena, or
+/// `Vec<u8>` can be converted without first copying into a `String`. Bytes
+/// outside `a`–`z` are left untouched.
+///
+/// # Example
+///
+/// ```rust
+/// let mut buf = *b"Hello";
+/// arm_string_ops::make_ascii_uppercase(&mut buf);
+/// assert_eq!(&buf, b"HELLO");
+/// ```
+pub fn make_ascii_uppercase(bytes: &mut [u8]) {
+    unsafe {
+        neon_to_upper(bytes.as_mut_ptr() as *mut c_char, bytes.len());
+    }
+}
+
+/// Convert ASCII letters to lowercase in-place in a byte slice.
+///
+/// The borrowed counterpart to [`to_lower_inplace`]; see
+/// [`make_ascii_uppercase`] for details.
+pub fn make_ascii_lowercase(bytes: &mut [u8]) {
+    unsafe {
         neon_to_lower(bytes.as_mut_ptr() as *mut c_char, bytes.len());
     }
 }
 
+/// Return a new `String` with ASCII letters uppercased, mirroring
+/// [`str::to_ascii_uppercase`] but backed by the SIMD kernel.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(arm_string_ops::to_ascii_uppercase("Hello"), "HELLO");
+/// ```
+pub fn to_ascii_uppercase(text: &str) -> String {
+    let mut bytes = text.as_bytes().to_vec();
+    make_ascii_uppercase(&mut bytes);
+    // Only ASCII letters were remapped, so the buffer is still valid UTF-8.
+    unsafe { String::from_utf8_unchecked(bytes) }
+}
+
+/// Return a new `String` with ASCII letters lowercased, mirroring
+/// [`str::to_ascii_lowercase`] but backed by the SIMD kernel.
+pub fn to_ascii_lowercase(text: &str) -> String {
+    let mut bytes = text.as_bytes().to_vec();
+    make_ascii_lowercase(&mut bytes);
+    unsafe { String::from_utf8_unchecked(bytes) }
+}
+
 
 /// Validate UTF-8 encoding
-/// 
-/// Returns `Ok(())` if the string contains valid UTF-8, otherwise returns an error.
-/// 
+///
+/// Returns `Ok(())` if the string contains valid UTF-8, otherwise returns a
+/// [`Utf8ValidationError`] describing where validation failed so callers can
+/// perform lossy recovery exactly like [`String::from_utf8_lossy`].
+///
 /// # Example
-/// 
+///
 /// ```rust
 /// assert!(utf8_validate("Hello 世界").is_ok());
 /// assert!(utf8_validate("Hello World").is_ok());
 /// ```
-pub fn utf8_validate(text: &str) -> Result<(), StringOpsError> {
-    unsafe {
-        let result = neon_utf8_validate(
-            text.as_ptr() as *const c_char,
-            text.len()
-        );
-        
-        if result == 1 {
-            Ok(())
-        } else {
-            Err(StringOpsError::InvalidUtf8)
-        }
+pub fn utf8_validate(text: &str) -> Result<(), Utf8ValidationError> {
+    utf8_validate_bytes(text.as_bytes())
+}
+
+/// Validate a raw byte buffer as UTF-8, reporting the offending offset.
+///
+/// Unlike [`utf8_validate`] this accepts arbitrary bytes (a `&str` is always
+/// valid UTF-8 already), which is what makes the `valid_up_to`/`error_len`
+/// reporting useful for recovering from untrusted input.
+pub fn utf8_validate_bytes(bytes: &[u8]) -> Result<(), Utf8ValidationError> {
+    // Fast-accept the common valid case with the NEON scanner; only when it
+    // rejects do we run a scalar pass to pin down `valid_up_to`/`error_len`.
+    let ok = unsafe { neon_utf8_validate(bytes.as_ptr() as *const c_char, bytes.len()) == 1 };
+    if ok {
+        return Ok(());
+    }
+    match std::str::from_utf8(bytes) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(Utf8ValidationError {
+            valid_up_to: e.valid_up_to(),
+            error_len: e.error_len(),
+        }),
     }
 }
 
@@ -130,23 +221,469 @@ pub fn utf8_char_count(text: &str) -> usize {
 }
 
 
+/// CESU-8 encoding variant.
+///
+/// `Standard` follows the plain CESU-8 rules, differing from UTF-8 only for
+/// supplementary characters. `Modified` additionally rewrites the NUL byte as
+/// the overlong sequence `0xC0 0x80` (Java's "Modified UTF-8"), so the output
+/// never contains an embedded zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    /// Plain CESU-8.
+    Standard,
+    /// Modified UTF-8: NUL is encoded as `0xC0 0x80`.
+    Modified,
+}
+
+/// Encode a string as CESU-8 (or Modified UTF-8).
+///
+/// Supplementary characters (≥ U+10000, 4 bytes in UTF-8) are emitted as a
+/// UTF-16 surrogate pair, each surrogate written as its own 3-byte sequence for
+/// six bytes total. Every other sequence is copied verbatim; under
+/// [`Variant::Modified`] a NUL byte becomes `0xC0 0x80`.
+///
+/// # Example
+///
+/// ```rust
+/// use arm_string_ops::{utf8_to_cesu8, Variant};
+/// // U+1F600 GRINNING FACE is 4 bytes in UTF-8, 6 bytes in CESU-8.
+/// assert_eq!(utf8_to_cesu8("😀", Variant::Standard).len(), 6);
+/// ```
+pub fn utf8_to_cesu8(text: &str, variant: Variant) -> Vec<u8> {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let lead = bytes[i];
+
+        if lead < 0x80 {
+            // ASCII fast path: bulk-copy the whole run. The only exception is
+            // NUL under the Modified variant.
+            if variant == Variant::Modified && lead == 0x00 {
+                out.extend_from_slice(&[0xC0, 0x80]);
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < bytes.len() && bytes[i] < 0x80 && !(variant == Variant::Modified && bytes[i] == 0x00) {
+                i += 1;
+            }
+            out.extend_from_slice(&bytes[start..i]);
+            continue;
+        }
+
+        // Width of the multibyte sequence, from the lead byte's high bits.
+        let width = if lead >= 0xF0 {
+            4
+        } else if lead >= 0xE0 {
+            3
+        } else {
+            2
+        };
+
+        if width == 4 {
+            let cp = (((lead & 0x07) as u32) << 18)
+                | (((bytes[i + 1] & 0x3F) as u32) << 12)
+                | (((bytes[i + 2] & 0x3F) as u32) << 6)
+                | ((bytes[i + 3] & 0x3F) as u32);
+            let v = cp - 0x10000;
+            let high = 0xD800 + (v >> 10);
+            let low = 0xDC00 + (v & 0x3FF);
+            push_surrogate(&mut out, high);
+            push_surrogate(&mut out, low);
+        } else {
+            out.extend_from_slice(&bytes[i..i + width]);
+        }
+        i += width;
+    }
+
+    out
+}
+
+/// Emit a 16-bit value as a 3-byte UTF-8-style sequence.
+fn push_surrogate(out: &mut Vec<u8>, v: u32) {
+    out.push(0xE0 | (v >> 12) as u8);
+    out.push(0x80 | ((v >> 6) & 0x3F) as u8);
+    out.push(0x80 | (v & 0x3F) as u8);
+}
+
+/// Decode CESU-8 / Modified UTF-8 back into a standard UTF-8 [`String`].
+///
+/// Surrogate pairs encoded as two 3-byte sequences are folded back into a
+/// single 4-byte UTF-8 sequence, and `0xC0 0x80` is decoded as NUL. A surrogate
+/// without its matching partner yields [`StringOpsError::UnpairedSurrogate`].
+///
+/// # Example
+///
+/// ```rust
+/// use arm_string_ops::{utf8_to_cesu8, cesu8_to_utf8, Variant};
+/// let cesu = utf8_to_cesu8("😀", Variant::Standard);
+/// assert_eq!(cesu8_to_utf8(&cesu).unwrap(), "😀");
+/// ```
+pub fn cesu8_to_utf8(bytes: &[u8]) -> Result<String, StringOpsError> {
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let lead = bytes[i];
+
+        if lead < 0x80 {
+            let start = i;
+            while i < bytes.len() && bytes[i] < 0x80 {
+                i += 1;
+            }
+            out.extend_from_slice(&bytes[start..i]);
+            continue;
+        }
+
+        // Modified UTF-8 NUL.
+        if lead == 0xC0 && i + 1 < bytes.len() && bytes[i + 1] == 0x80 {
+            out.push(0x00);
+            i += 2;
+            continue;
+        }
+
+        let width = if lead >= 0xF0 {
+            4
+        } else if lead >= 0xE0 {
+            3
+        } else {
+            2
+        };
+        if i + width > bytes.len() {
+            return Err(StringOpsError::InvalidUtf8);
+        }
+
+        if width == 3 {
+            let v = (((lead & 0x0F) as u32) << 12)
+                | (((bytes[i + 1] & 0x3F) as u32) << 6)
+                | ((bytes[i + 2] & 0x3F) as u32);
+            if (0xD800..=0xDBFF).contains(&v) {
+                // High surrogate: a low surrogate must follow.
+                if i + 6 > bytes.len() || bytes[i + 3] < 0xE0 {
+                    return Err(StringOpsError::UnpairedSurrogate);
+                }
+                let low = (((bytes[i + 3] & 0x0F) as u32) << 12)
+                    | (((bytes[i + 4] & 0x3F) as u32) << 6)
+                    | ((bytes[i + 5] & 0x3F) as u32);
+                if !(0xDC00..=0xDFFF).contains(&low) {
+                    return Err(StringOpsError::UnpairedSurrogate);
+                }
+                let cp = 0x10000 + ((v - 0xD800) << 10) + (low - 0xDC00);
+                out.push(0xF0 | (cp >> 18) as u8);
+                out.push(0x80 | ((cp >> 12) & 0x3F) as u8);
+                out.push(0x80 | ((cp >> 6) & 0x3F) as u8);
+                out.push(0x80 | (cp & 0x3F) as u8);
+                i += 6;
+                continue;
+            } else if (0xDC00..=0xDFFF).contains(&v) {
+                return Err(StringOpsError::UnpairedSurrogate);
+            }
+        }
+
+        out.extend_from_slice(&bytes[i..i + width]);
+        i += width;
+    }
+
+    String::from_utf8(out).map_err(|_| StringOpsError::InvalidUtf8)
+}
+
+
+/// Strictly validate a byte buffer as UTF-8.
+///
+/// In addition to the structural checks performed by [`utf8_validate_bytes`],
+/// this rejects the malformed classes a length-only scanner can miss: overlong
+/// encodings (`0xC0`/`0xC1` leads, `E0 80..9F`, `F0 80..8F`), UTF-16 surrogate
+/// code points (`ED A0..BF ..`, U+D800–U+DFFF), and code points above U+10FFFF
+/// (`F4 90..`, `F5..F7` leads). A scalar pass checks each lead byte's allowed
+/// second-byte range and the remaining continuation bytes, matching the
+/// guarantees of the Rust core library.
+///
+/// # Example
+///
+/// ```rust
+/// use arm_string_ops::utf8_validate_strict;
+/// assert!(utf8_validate_strict("Hello 世界".as_bytes()).is_ok());
+/// // Overlong encoding of '/' — rejected.
+/// assert!(utf8_validate_strict(&[0xC0, 0xAF]).is_err());
+/// ```
+pub fn utf8_validate_strict(bytes: &[u8]) -> Result<(), Utf8ValidationError> {
+    let mut i = 0;
+    while i < bytes.len() {
+        let lead = bytes[i];
+
+        if lead < 0x80 {
+            i += 1;
+            continue;
+        }
+
+        // Allowed second-byte range per lead byte (Unicode Table 3-7).
+        let (width, lo, hi) = match lead {
+            0xC2..=0xDF => (2, 0x80, 0xBF),
+            0xE0 => (3, 0xA0, 0xBF),
+            0xE1..=0xEC => (3, 0x80, 0xBF),
+            0xED => (3, 0x80, 0x9F), // excludes surrogates ED A0..BF
+            0xEE..=0xEF => (3, 0x80, 0xBF),
+            0xF0 => (4, 0x90, 0xBF),
+            0xF1..=0xF3 => (4, 0x80, 0xBF),
+            0xF4 => (4, 0x80, 0x8F), // excludes > U+10FFFF
+            // 0xC0/0xC1 (overlong) and 0xF5..=0xFF (out of range) are invalid leads.
+            _ => {
+                return Err(Utf8ValidationError { valid_up_to: i, error_len: Some(1) });
+            }
+        };
+
+        // Check the bytes that are present before reporting a truncated
+        // sequence, so an invalid byte near the end still gets an `error_len`.
+        for k in 1..width {
+            let b = match bytes.get(i + k) {
+                Some(&b) => b,
+                None => return Err(Utf8ValidationError { valid_up_to: i, error_len: None }),
+            };
+            // Second byte against its lead-specific range; the rest are 0x80..=0xBF.
+            let (min, max) = if k == 1 { (lo, hi) } else { (0x80, 0xBF) };
+            if b < min || b > max {
+                return Err(Utf8ValidationError { valid_up_to: i, error_len: Some(k) });
+            }
+        }
+
+        i += width;
+    }
+
+    Ok(())
+}
+
+/// Compare two byte buffers for equality, ignoring ASCII letter case.
+///
+/// Applies the same ASCII case-folding rule as the `neon_to_lower` kernel to
+/// each byte and compares, short-circuiting on the first mismatch. Neither
+/// input is allocated or mutated.
+///
+/// # Example
+///
+/// ```rust
+/// use arm_string_ops::eq_ignore_ascii_case;
+/// assert!(eq_ignore_ascii_case(b"Content-Type", b"content-type"));
+/// assert!(!eq_ignore_ascii_case(b"Accept", b"Accept-Encoding"));
+/// ```
+pub fn eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).all(|(x, y)| ascii_fold(*x) == ascii_fold(*y))
+}
+
+/// Locate `needle` within `haystack`, ignoring ASCII letter case.
+///
+/// Returns the byte offset of the first case-insensitive match, or `None`.
+///
+/// # Example
+///
+/// ```rust
+/// use arm_string_ops::find_ignore_ascii_case;
+/// assert_eq!(find_ignore_ascii_case(b"Hello World", b"WORLD"), Some(6));
+/// assert_eq!(find_ignore_ascii_case(b"Hello", b"xyz"), None);
+/// ```
+pub fn find_ignore_ascii_case(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    if needle.len() > haystack.len() {
+        return None;
+    }
+    (0..=haystack.len() - needle.len())
+        .find(|&i| eq_ignore_ascii_case(&haystack[i..i + needle.len()], needle))
+}
+
+/// Fold a single byte to its lowercase ASCII form, matching the SIMD kernel.
+#[inline]
+fn ascii_fold(b: u8) -> u8 {
+    if b.is_ascii_uppercase() {
+        b + 32
+    } else {
+        b
+    }
+}
+
+/// Decode ISO-8859-1 (Latin-1) bytes into a UTF-8 [`String`].
+///
+/// Every byte ≥ `0x80` maps to a two-byte UTF-8 sequence; runs of pure-ASCII
+/// bytes (no high bit set) are bulk-copied unchanged, falling to the scalar
+/// expansion path only when a high byte is encountered.
+///
+/// # Example
+///
+/// ```rust
+/// use arm_string_ops::latin1_to_utf8;
+/// // 0xE9 is 'é' in Latin-1.
+/// assert_eq!(latin1_to_utf8(&[b'c', b'a', b'f', 0xE9]), "café");
+/// ```
+pub fn latin1_to_utf8(bytes: &[u8]) -> String {
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] < 0x80 {
+            // ASCII fast path: bulk-copy the run in one shot.
+            let start = i;
+            while i < bytes.len() && bytes[i] < 0x80 {
+                i += 1;
+            }
+            out.extend_from_slice(&bytes[start..i]);
+        } else {
+            let b = bytes[i];
+            out.push(0xC0 | (b >> 6));
+            out.push(0x80 | (b & 0x3F));
+            i += 1;
+        }
+    }
+
+    // Every branch emits well-formed UTF-8 by construction.
+    unsafe { String::from_utf8_unchecked(out) }
+}
+
+/// Encode a string as ISO-8859-1 (Latin-1) bytes.
+///
+/// Reverses [`latin1_to_utf8`], returning [`StringOpsError::NonLatin1`] if any
+/// code point exceeds U+00FF and therefore has no Latin-1 representation.
+///
+/// # Example
+///
+/// ```rust
+/// use arm_string_ops::utf8_to_latin1;
+/// assert_eq!(utf8_to_latin1("café").unwrap(), vec![b'c', b'a', b'f', 0xE9]);
+/// assert!(utf8_to_latin1("世界").is_err());
+/// ```
+pub fn utf8_to_latin1(text: &str) -> Result<Vec<u8>, StringOpsError> {
+    let mut out = Vec::with_capacity(text.len());
+    for ch in text.chars() {
+        let cp = ch as u32;
+        if cp > 0xFF {
+            return Err(StringOpsError::NonLatin1);
+        }
+        out.push(cp as u8);
+    }
+    Ok(out)
+}
+
 /// Trait for string slice extensions
 pub trait StringOpsExt {
+    /// Owned, case-converted form of this slice (`String` for `str`,
+    /// `Vec<u8>` for `[u8]`).
+    type Owned;
+
     /// Validate UTF-8 encoding
-    fn validate_utf8(&self) -> Result<(), StringOpsError>;
-    
+    fn validate_utf8(&self) -> Result<(), Utf8ValidationError>;
+
+    /// Strictly validate UTF-8, rejecting overlong encodings, surrogates, and
+    /// out-of-range code points
+    fn validate_utf8_strict(&self) -> Result<(), Utf8ValidationError>;
+
     /// Count UTF-8 characters
     fn char_count_utf8(&self) -> usize;
+
+    /// Uppercase ASCII letters in-place using the SIMD kernel
+    fn make_ascii_upper(&mut self);
+
+    /// Lowercase ASCII letters in-place using the SIMD kernel
+    fn make_ascii_lower(&mut self);
+
+    /// Return an owned copy with ASCII letters uppercased
+    fn to_ascii_upper(&self) -> Self::Owned;
+
+    /// Return an owned copy with ASCII letters lowercased
+    fn to_ascii_lower(&self) -> Self::Owned;
+
+    /// Compare with `other` for equality, ignoring ASCII letter case
+    fn eq_ignore_case(&self, other: &Self) -> bool;
+
+    /// Find `needle` within this slice, ignoring ASCII letter case
+    fn find_ignore_case(&self, needle: &Self) -> Option<usize>;
 }
 
 impl StringOpsExt for str {
-    fn validate_utf8(&self) -> Result<(), StringOpsError> {
+    type Owned = String;
+
+    fn validate_utf8(&self) -> Result<(), Utf8ValidationError> {
         utf8_validate(self)
     }
-    
+
+    fn validate_utf8_strict(&self) -> Result<(), Utf8ValidationError> {
+        utf8_validate_strict(self.as_bytes())
+    }
+
     fn char_count_utf8(&self) -> usize {
         utf8_char_count(self)
     }
+
+    fn make_ascii_upper(&mut self) {
+        unsafe { make_ascii_uppercase(self.as_bytes_mut()) }
+    }
+
+    fn make_ascii_lower(&mut self) {
+        unsafe { make_ascii_lowercase(self.as_bytes_mut()) }
+    }
+
+    fn to_ascii_upper(&self) -> String {
+        to_ascii_uppercase(self)
+    }
+
+    fn to_ascii_lower(&self) -> String {
+        to_ascii_lowercase(self)
+    }
+
+    fn eq_ignore_case(&self, other: &str) -> bool {
+        eq_ignore_ascii_case(self.as_bytes(), other.as_bytes())
+    }
+
+    fn find_ignore_case(&self, needle: &str) -> Option<usize> {
+        find_ignore_ascii_case(self.as_bytes(), needle.as_bytes())
+    }
+}
+
+impl StringOpsExt for [u8] {
+    type Owned = Vec<u8>;
+
+    fn validate_utf8(&self) -> Result<(), Utf8ValidationError> {
+        utf8_validate_bytes(self)
+    }
+
+    fn validate_utf8_strict(&self) -> Result<(), Utf8ValidationError> {
+        utf8_validate_strict(self)
+    }
+
+    fn char_count_utf8(&self) -> usize {
+        unsafe { neon_utf8_count_chars(self.as_ptr() as *const c_char, self.len()) }
+    }
+
+    fn make_ascii_upper(&mut self) {
+        make_ascii_uppercase(self)
+    }
+
+    fn make_ascii_lower(&mut self) {
+        make_ascii_lowercase(self)
+    }
+
+    fn to_ascii_upper(&self) -> Vec<u8> {
+        let mut bytes = self.to_vec();
+        make_ascii_uppercase(&mut bytes);
+        bytes
+    }
+
+    fn to_ascii_lower(&self) -> Vec<u8> {
+        let mut bytes = self.to_vec();
+        make_ascii_lowercase(&mut bytes);
+        bytes
+    }
+
+    fn eq_ignore_case(&self, other: &[u8]) -> bool {
+        eq_ignore_ascii_case(self, other)
+    }
+
+    fn find_ignore_case(&self, needle: &[u8]) -> Option<usize> {
+        find_ignore_ascii_case(self, needle)
+    }
 }
 
 #[cfg(test)]
@@ -180,4 +717,86 @@ mod tests {
         assert!(text.validate_utf8().is_ok());
         assert_eq!(text.char_count_utf8(), 11);
     }
+
+    #[test]
+    fn test_slice_case_conversion() {
+        let mut buf = *b"Hello World!";
+        make_ascii_uppercase(&mut buf);
+        assert_eq!(&buf, b"HELLO WORLD!");
+        make_ascii_lowercase(&mut buf);
+        assert_eq!(&buf, b"hello world!");
+
+        assert_eq!(to_ascii_uppercase("Hello"), "HELLO");
+        assert_eq!(to_ascii_lowercase("HeLLo"), "hello");
+        assert_eq!(b"Mixed".to_ascii_upper(), b"MIXED".to_vec());
+    }
+
+    #[test]
+    fn test_latin1_roundtrip() {
+        let latin1 = [b'c', b'a', b'f', 0xE9];
+        assert_eq!(latin1_to_utf8(&latin1), "café");
+        assert_eq!(utf8_to_latin1("café").unwrap(), latin1.to_vec());
+        assert_eq!(latin1_to_utf8(b"plain ascii"), "plain ascii");
+        assert_eq!(utf8_to_latin1("世界"), Err(StringOpsError::NonLatin1));
+    }
+
+    #[test]
+    fn test_eq_and_find_ignore_ascii_case() {
+        assert!(eq_ignore_ascii_case(b"Content-Type", b"content-type"));
+        assert!(!eq_ignore_ascii_case(b"Accept", b"Accept-Encoding"));
+        assert!("Host".eq_ignore_case("HOST"));
+
+        assert_eq!(find_ignore_ascii_case(b"Hello World", b"WORLD"), Some(6));
+        assert_eq!(find_ignore_ascii_case(b"Hello", b"xyz"), None);
+        assert_eq!("abcDEF".find_ignore_case("cde"), Some(2));
+    }
+
+    #[test]
+    fn test_utf8_validation_error_offset() {
+        // "A" + bare continuation byte 0x80: 1 valid byte, a 1-byte error.
+        let err = utf8_validate_bytes(&[b'A', 0x80]).unwrap_err();
+        assert_eq!(err.valid_up_to, 1);
+        assert_eq!(err.error_len, Some(1));
+
+        // Truncated 3-byte lead ends mid-character.
+        let err = utf8_validate_bytes(&[0xE2, 0x82]).unwrap_err();
+        assert_eq!(err.valid_up_to, 0);
+        assert_eq!(err.error_len, None);
+    }
+
+    #[test]
+    fn test_utf8_validate_strict() {
+        assert!(utf8_validate_strict("Hello 世界 😀".as_bytes()).is_ok());
+        // Overlong '/' (C0 AF), surrogate U+D800 (ED A0 80), and > U+10FFFF (F4 90 80 80).
+        assert!(utf8_validate_strict(&[0xC0, 0xAF]).is_err());
+        assert!(utf8_validate_strict(&[0xED, 0xA0, 0x80]).is_err());
+        assert!(utf8_validate_strict(&[0xF4, 0x90, 0x80, 0x80]).is_err());
+        assert!("café".validate_utf8_strict().is_ok());
+    }
+
+    #[test]
+    fn test_cesu8_roundtrip() {
+        // ASCII and BMP characters are unchanged; supplementary characters
+        // expand to six bytes and round-trip back.
+        for s in ["Hello", "café", "世界", "😀 grin", "a😀b世c"] {
+            let cesu = utf8_to_cesu8(s, Variant::Standard);
+            assert_eq!(cesu8_to_utf8(&cesu).unwrap(), s);
+        }
+        assert_eq!(utf8_to_cesu8("😀", Variant::Standard).len(), 6);
+    }
+
+    #[test]
+    fn test_modified_utf8_nul() {
+        let cesu = utf8_to_cesu8("a\0b", Variant::Modified);
+        assert_eq!(cesu, vec![b'a', 0xC0, 0x80, b'b']);
+        assert!(!cesu.contains(&0x00));
+        assert_eq!(cesu8_to_utf8(&cesu).unwrap(), "a\0b");
+    }
+
+    #[test]
+    fn test_cesu8_unpaired_surrogate() {
+        // A lone high surrogate (0xED 0xA0 0x80 = U+D800) has no partner.
+        let lone = vec![0xED, 0xA0, 0x80];
+        assert_eq!(cesu8_to_utf8(&lone), Err(StringOpsError::UnpairedSurrogate));
+    }
 }
\ No newline at end of file